@@ -0,0 +1,31 @@
+//! Extra bookkeeping for panic messages, enabled by the `diagnostics` cargo feature.
+//!
+//! When enabled, every successful `borrow`/`borrow_mut` records where it was taken, so a
+//! later conflicting borrow can point at the culprit, following the `BorrowInfo` technique
+//! used by erg_common's `Shared`.
+
+use std::fmt;
+use std::panic::Location;
+
+/// Where and on which thread the most recent successful borrow was taken.
+#[derive(Debug, Clone)]
+pub(crate) struct BorrowInfo {
+    location: &'static Location<'static>,
+    thread_name: String,
+}
+
+impl BorrowInfo {
+    #[track_caller]
+    pub(crate) fn capture() -> Self {
+        Self {
+            location: Location::caller(),
+            thread_name: std::thread::current().name().unwrap_or("<unnamed>").to_string(),
+        }
+    }
+}
+
+impl fmt::Display for BorrowInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on thread {}", self.location, self.thread_name)
+    }
+}
@@ -0,0 +1,56 @@
+//! Error types returned by the non-panicking `try_*` APIs.
+
+use std::fmt;
+use std::thread::ThreadId;
+
+/// The error returned by the `try_*` family of methods when a borrow or
+/// thread-ownership check fails.
+///
+/// The panicking APIs (`borrow`, `borrow_mut`, `set`, `get`, `replace`, ...)
+/// are thin wrappers that unwrap this error into a panic with an equivalent
+/// message.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BorrowError {
+    /// The cell was accessed from a thread other than the one that owns it.
+    WrongThread {
+        /// The thread that is allowed to access the cell.
+        expected: ThreadId,
+        /// The thread that attempted the access.
+        actual: ThreadId,
+    },
+    /// The value was already (immutably or mutably) borrowed.
+    AlreadyBorrowed,
+    /// The value was already mutably borrowed.
+    AlreadyMutablyBorrowed,
+}
+
+impl BorrowError {
+    /// Panics with the same message the old infallible APIs used.
+    #[track_caller]
+    #[cold]
+    pub(crate) fn panic(self) -> ! {
+        match self {
+            BorrowError::WrongThread { actual, .. } => {
+                panic!("Access single thread cell with different thread id {:?}", actual)
+            }
+            BorrowError::AlreadyBorrowed => panic!("already borrowed"),
+            BorrowError::AlreadyMutablyBorrowed => panic!("already mutably borrowed"),
+        }
+    }
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BorrowError::WrongThread { expected, actual } => write!(
+                f,
+                "access single thread cell with different thread id, expected {:?} but got {:?}",
+                expected, actual
+            ),
+            BorrowError::AlreadyBorrowed => write!(f, "already borrowed"),
+            BorrowError::AlreadyMutablyBorrowed => write!(f, "already mutably borrowed"),
+        }
+    }
+}
+
+impl std::error::Error for BorrowError {}
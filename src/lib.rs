@@ -5,12 +5,19 @@
 //! The only exception is drop. The cell does not implement [`Send`] if `T` is not [`Send`].
 //! So that the cell cannot be sent to another thread to drop.
 //! It is obvious that if `T` is [`Send`], it is safe to drop in the other thread.
+#![cfg_attr(feature = "unsize", feature(coerce_unsized, unsize))]
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+pub mod error;
 pub mod types;
+pub use error::*;
 pub use types::*;
 
+#[cfg(feature = "diagnostics")]
+use diagnostics::BorrowInfo;
 use std::cell::UnsafeCell;
 use std::marker::PhantomData;
-use std::mem;
+use std::mem::{self, ManuallyDrop};
 use std::ops::{Deref, DerefMut};
 use std::ptr::NonNull;
 use std::thread::ThreadId;
@@ -30,32 +37,38 @@ fn is_reading(x: BorrowFlag) -> bool {
 
 #[track_caller]
 #[cold]
-fn panic_already_borrowed() -> ! {
-    panic!("already borrowed")
+fn panic_too_many_immutable_borrows() -> ! {
+    panic!("too many immutable borrows")
 }
 
 #[track_caller]
 #[cold]
-fn panic_already_mutably_borrowed() -> ! {
-    panic!("already mutably borrowed")
+fn panic_too_many_mutable_borrows() -> ! {
+    panic!("too many mutable borrows")
 }
 
 
 /// A mutable memory location. Can only be accessed by the owner thread.
 ///
 /// If you access the cell from a different thread, the thread will be panicked.
-pub struct SingleThreadCell<T> {
-    value: UnsafeCell<T>,
+pub struct SingleThreadCell<T: ?Sized> {
     owner_thread: ThreadId,
+    value: UnsafeCell<T>,
 }
 
-impl<T> SingleThreadType for SingleThreadCell<T> {
+impl<T: ?Sized> SingleThreadType for SingleThreadCell<T> {
     /// Get the owner thread that owns this type.
     fn get_owner_thread_id(&self) -> ThreadId {
         self.owner_thread
     }
 }
 
+impl<T: ?Sized> Rebindable for SingleThreadCell<T> {
+    fn set_owner_thread_id(&mut self, id: ThreadId) {
+        self.owner_thread = id;
+    }
+}
+
 
 impl<T> SingleThreadCell<T> {
     /// Creates a new Cell containing the given value.
@@ -73,10 +86,21 @@ impl<T> SingleThreadCell<T> {
     /// # Panics
     /// This function will panic if access from different thread
     #[inline]
+    #[track_caller]
     pub fn set(&self, value: T) {
-        self.check_thread_panic();
+        match self.try_set(value) {
+            Ok(()) => {}
+            Err(e) => e.panic(),
+        }
+    }
+
+    /// Set the contained value, returning an error instead of panicking if accessed from a different thread.
+    #[inline]
+    pub fn try_set(&self, value: T) -> Result<(), BorrowError> {
+        self.try_check_thread()?;
         // SAFETY: We checked the thread.
         unsafe { *self.value.get() = value; }
+        Ok(())
     }
 
     /// Replace the contained value, and return the old contained value.
@@ -84,48 +108,140 @@ impl<T> SingleThreadCell<T> {
     /// # Panics
     /// This function will panic if access from different thread
     #[inline]
+    #[track_caller]
     pub fn replace(&self, value: T) -> T {
-        self.check_thread_panic();
+        match self.try_replace(value) {
+            Ok(old) => old,
+            Err(e) => e.panic(),
+        }
+    }
+
+    /// Replace the contained value, returning an error instead of panicking if accessed from a different thread.
+    #[inline]
+    pub fn try_replace(&self, value: T) -> Result<T, BorrowError> {
+        self.try_check_thread()?;
         // SAFETY: We checked the thread.
-        mem::replace(unsafe { &mut *self.value.get() }, value)
+        Ok(mem::replace(unsafe { &mut *self.value.get() }, value))
+    }
+
+    /// Takes the value out of the cell, leaving `Default::default()` in its place.
+    ///
+    /// Since this call borrows the cell mutably, no thread check is needed.
+    #[inline]
+    pub fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        mem::take(self.get_mut())
+    }
+
+    /// Replaces the contained value by computing it from the current one, returning the
+    /// previous value.
+    ///
+    /// Since this call borrows the cell mutably, no thread check is needed.
+    #[inline]
+    pub fn replace_with<F>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&mut T) -> T,
+    {
+        let value = self.get_mut();
+        let replacement = f(value);
+        mem::replace(value, replacement)
+    }
+
+    /// Swaps the values of two cells, checking both cells' owner threads once up front.
+    ///
+    /// # Panics
+    /// This function will panic if either cell is accessed from a thread other than its owner.
+    #[track_caller]
+    pub fn swap(&self, other: &Self) {
+        if std::ptr::eq(self, other) {
+            return;
+        }
+        self.check_thread_panic();
+        other.check_thread_panic();
+        // SAFETY: both cells checked their owner thread, and `self` and `other` are distinct
+        // cells (checked above), so the two pointers cannot alias.
+        unsafe { std::ptr::swap(self.value.get(), other.value.get()) };
+    }
+}
+
+impl<T: ?Sized> SingleThreadCell<T> {
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// Since this call requires exclusive access to the cell already, no thread check is
+    /// needed: the existence of `&mut self` proves no other thread can be concurrently
+    /// accessing it.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
     }
 }
 
-pub struct SingleThreadRefCell<T> {
+impl<T> SingleThreadCell<T> {
+    /// Consumes the cell, returning the wrapped value.
+    ///
+    /// No thread check is needed since ownership of `self` proves exclusive access.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+pub struct SingleThreadRefCell<T: ?Sized> {
     borrow: UnsafeCell<BorrowFlag>,
-    value: UnsafeCell<T>,
+    #[cfg(feature = "diagnostics")]
+    borrow_location: UnsafeCell<Option<BorrowInfo>>,
     owner_thread: ThreadId,
+    value: UnsafeCell<T>,
 }
 
 impl<T> SingleThreadRefCell<T> {
     pub fn new(val: T) -> Self {
         Self {
             borrow: UnsafeCell::new(UNUSED),
+            #[cfg(feature = "diagnostics")]
+            borrow_location: UnsafeCell::new(None),
             value: UnsafeCell::new(val),
             owner_thread: std::thread::current().id(),
         }
     }
 }
 
-impl<T> SingleThreadRefCell<T> {
+impl<T: ?Sized> SingleThreadRefCell<T> {
     /// Immutably borrows the wrapped value, returning an error if the value is currently mutably borrowed.
     ///
     /// The borrow lasts until the returned Ref exits scope. Multiple immutable borrows can be taken out at the same time.
     ///
     /// # Panics
     /// This function will panic if access from different thread, or already borrowed
+    #[track_caller]
     pub fn borrow(&self) -> SingleThreadRef<'_, T> {
-        self.check_thread_panic();
+        match self.try_borrow() {
+            Ok(b) => b,
+            Err(e) => self.panic_borrow_error(e),
+        }
+    }
+
+    /// Immutably borrows the wrapped value, returning a [`BorrowError`] instead of panicking
+    /// on a wrong-thread access or a conflicting mutable borrow.
+    #[track_caller]
+    pub fn try_borrow(&self) -> Result<SingleThreadRef<'_, T>, BorrowError> {
+        self.try_check_thread()?;
 
         // We checked the thread.
-        match unsafe { BorrowRef::new(&self.borrow) } {
+        match unsafe {
+            BorrowRef::new(
+                &self.borrow,
+                #[cfg(feature = "diagnostics")]
+                &self.borrow_location,
+            )
+        } {
             Some(b) => {
                 let value = unsafe { NonNull::new_unchecked(self.value.get()) };
-                SingleThreadRef { value, _borrow: b, marker: Default::default() }
-            }
-            None => {
-                panic_already_mutably_borrowed()
+                Ok(SingleThreadRef { value, _borrow: b, marker: Default::default() })
             }
+            None => Err(BorrowError::AlreadyMutablyBorrowed),
         }
     }
 
@@ -133,62 +249,175 @@ impl<T> SingleThreadRefCell<T> {
     ///
     /// # Panics
     /// This function will panic if access from different thread, or already borrowed
+    #[track_caller]
     pub fn borrow_mut(&self) -> SingleThreadRefMut<'_, T> {
-        self.check_thread_panic();
+        match self.try_borrow_mut() {
+            Ok(b) => b,
+            Err(e) => self.panic_borrow_error(e),
+        }
+    }
+
+    /// Mutably borrows the wrapped value, returning a [`BorrowError`] instead of panicking
+    /// on a wrong-thread access or a conflicting borrow.
+    #[track_caller]
+    pub fn try_borrow_mut(&self) -> Result<SingleThreadRefMut<'_, T>, BorrowError> {
+        self.try_check_thread()?;
         // We checked the thread.
-        match unsafe { BorrowRefMut::new(&self.borrow) } {
+        match unsafe {
+            BorrowRefMut::new(
+                &self.borrow,
+                #[cfg(feature = "diagnostics")]
+                &self.borrow_location,
+            )
+        } {
             Some(b) => {
                 // SAFETY: `BorrowRefMut` guarantees unique access.
                 let value = unsafe { NonNull::new_unchecked(self.value.get()) };
-                SingleThreadRefMut { value, _borrow: b, marker: PhantomData }
+                Ok(SingleThreadRefMut { value, _borrow: b, marker: PhantomData })
             }
-            None => {
-                panic_already_borrowed();
+            None => Err(BorrowError::AlreadyBorrowed),
+        }
+    }
+
+    /// Panics for a borrow conflict, including the location and thread name of the most
+    /// recent successful borrow when the `diagnostics` feature is enabled.
+    #[cold]
+    #[track_caller]
+    fn panic_borrow_error(&self, e: BorrowError) -> ! {
+        #[cfg(feature = "diagnostics")]
+        {
+            let previous = unsafe { &*self.borrow_location.get() };
+            if let Some(previous) = previous {
+                match e {
+                    BorrowError::AlreadyBorrowed => {
+                        panic!("already borrowed; previous borrow at {}", previous)
+                    }
+                    BorrowError::AlreadyMutablyBorrowed => {
+                        panic!("already mutably borrowed; previous borrow at {}", previous)
+                    }
+                    BorrowError::WrongThread { .. } => {}
+                }
             }
         }
+        e.panic()
     }
 }
 
-impl<T> SingleThreadType for SingleThreadRefCell<T> {
+impl<T: ?Sized> SingleThreadRefCell<T> {
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// Since this call requires exclusive access to the cell already, no thread or borrow
+    /// check is needed: the existence of `&mut self` proves there are no outstanding borrows.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T> SingleThreadRefCell<T> {
+    /// Consumes the cell, returning the wrapped value.
+    ///
+    /// No thread or borrow check is needed since ownership of `self` proves exclusive access.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> SingleThreadType for SingleThreadRefCell<T> {
     fn get_owner_thread_id(&self) -> ThreadId {
         self.owner_thread
     }
 }
 
+impl<T: ?Sized> Rebindable for SingleThreadRefCell<T> {
+    fn set_owner_thread_id(&mut self, id: ThreadId) {
+        self.owner_thread = id;
+    }
+}
+
 impl<T: Copy> SingleThreadCell<T> {
     /// Returns a copy of the contained value.
     #[inline]
+    #[track_caller]
     pub fn get(&self) -> T {
-        self.check_thread_panic();
+        match self.try_get() {
+            Ok(v) => v,
+            Err(e) => e.panic(),
+        }
+    }
+
+    /// Returns a copy of the contained value, returning an error instead of panicking if accessed from a different thread.
+    #[inline]
+    pub fn try_get(&self) -> Result<T, BorrowError> {
+        self.try_check_thread()?;
         // SAFETY: We checked the thread.
-        unsafe { *self.value.get() }
+        Ok(unsafe { *self.value.get() })
     }
 }
 
-unsafe impl<T> Sync for SingleThreadCell<T> {}
-unsafe impl<T> Sync for SingleThreadRefCell<T> {}
+unsafe impl<T: ?Sized> Sync for SingleThreadCell<T> {}
+unsafe impl<T: ?Sized> Sync for SingleThreadRefCell<T> {}
 
 struct BorrowRef<'a> {
     borrow: &'a UnsafeCell<BorrowFlag>,
+    #[cfg(feature = "diagnostics")]
+    location: &'a UnsafeCell<Option<BorrowInfo>>,
 }
 
 impl<'b> BorrowRef<'b> {
     #[inline]
+    #[track_caller]
     /// Outside should keep the borrow in the same thread.
-    unsafe fn new(borrow: &'b UnsafeCell<BorrowFlag>) -> Option<BorrowRef<'b>> {
+    unsafe fn new(
+        borrow: &'b UnsafeCell<BorrowFlag>,
+        #[cfg(feature = "diagnostics")] location: &'b UnsafeCell<Option<BorrowInfo>>,
+    ) -> Option<BorrowRef<'b>> {
         let b = (*borrow.get()).wrapping_add(1);
         if !is_reading(b) {
             // Writing or overflow.
             None
         } else {
             *borrow.get() = b;
-            Some(BorrowRef { borrow })
+            #[cfg(feature = "diagnostics")]
+            {
+                *location.get() = Some(BorrowInfo::capture());
+            }
+            Some(BorrowRef {
+                borrow,
+                #[cfg(feature = "diagnostics")]
+                location,
+            })
+        }
+    }
+
+    #[inline]
+    /// Duplicates the shared borrow, bumping the read count. The caller must already hold
+    /// a live `BorrowRef` over the same flag, i.e. it currently holds a valid read count.
+    fn clone(&self) -> BorrowRef<'b> {
+        // SAFETY: `self` is a live BorrowRef, so `self.borrow` currently holds a valid read
+        // count, and it may only be touched from this thread.
+        unsafe {
+            let borrow = *self.borrow.get();
+            debug_assert!(is_reading(borrow));
+            let new_borrow = borrow.wrapping_add(1);
+            if !is_reading(new_borrow) {
+                panic_too_many_immutable_borrows();
+            }
+            *self.borrow.get() = new_borrow;
+        }
+        BorrowRef {
+            borrow: self.borrow,
+            #[cfg(feature = "diagnostics")]
+            location: self.location,
         }
     }
 }
 
 struct BorrowRefMut<'b> {
     borrow: &'b UnsafeCell<BorrowFlag>,
+    #[cfg(feature = "diagnostics")]
+    location: &'b UnsafeCell<Option<BorrowInfo>>,
     // Mark this is not send or sync
     marker: PhantomData<std::rc::Rc<()>>,
 }
@@ -196,7 +425,11 @@ struct BorrowRefMut<'b> {
 impl<'b> BorrowRefMut<'b> {
     // Outside should keep the borrow in the same thread.
     #[inline]
-    unsafe fn new(borrow: &'b UnsafeCell<BorrowFlag>) -> Option<BorrowRefMut<'b>> {
+    #[track_caller]
+    unsafe fn new(
+        borrow: &'b UnsafeCell<BorrowFlag>,
+        #[cfg(feature = "diagnostics")] location: &'b UnsafeCell<Option<BorrowInfo>>,
+    ) -> Option<BorrowRefMut<'b>> {
         // NOTE: Unlike BorrowRefMut::clone, new is called to create the initial
         // mutable reference, and so there must currently be no existing
         // references. Thus, while clone increments the mutable refcount, here
@@ -204,14 +437,45 @@ impl<'b> BorrowRefMut<'b> {
         match *borrow.get() {
             UNUSED => {
                 *borrow.get() = UNUSED - 1;
-                Some(BorrowRefMut { borrow: borrow, marker: Default::default() })
+                #[cfg(feature = "diagnostics")]
+                {
+                    *location.get() = Some(BorrowInfo::capture());
+                }
+                Some(BorrowRefMut {
+                    borrow: borrow,
+                    #[cfg(feature = "diagnostics")]
+                    location,
+                    marker: Default::default(),
+                })
             }
             _ => None,
         }
     }
+
+    #[inline]
+    /// Duplicates the exclusive borrow, pushing the write count one level deeper. The caller
+    /// must already hold a live `BorrowRefMut` over the same flag.
+    fn clone(&self) -> BorrowRefMut<'b> {
+        // SAFETY: `self` is a live BorrowRefMut, so `self.borrow` currently holds a valid
+        // write count, and it may only be touched from this thread.
+        unsafe {
+            let borrow = *self.borrow.get();
+            debug_assert!(is_writing(borrow));
+            if borrow == BorrowFlag::MIN {
+                panic_too_many_mutable_borrows();
+            }
+            *self.borrow.get() = borrow - 1;
+        }
+        BorrowRefMut {
+            borrow: self.borrow,
+            #[cfg(feature = "diagnostics")]
+            location: self.location,
+            marker: PhantomData,
+        }
+    }
 }
 
-pub struct SingleThreadRef<'a, T: 'a> {
+pub struct SingleThreadRef<'a, T: ?Sized + 'a> {
     value: NonNull<T>,
     _borrow: BorrowRef<'a>,
     // Mark this is not send or sync
@@ -223,6 +487,121 @@ pub struct SingleThreadRefMut<'b, T: ?Sized + 'b> {
     marker: PhantomData<&'b mut T>,
 }
 
+impl<'b, T: ?Sized> SingleThreadRef<'b, T> {
+    /// Copies a `SingleThreadRef`, incrementing the shared borrow count.
+    ///
+    /// This is an associated function that needs to be used as `SingleThreadRef::clone(...)`,
+    /// rather than `orig.clone()`, so that it does not shadow a `clone` method on the
+    /// contents of `SingleThreadRef` used through `Deref` (mirrors `std::cell::Ref::clone`).
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(orig: &Self) -> Self {
+        SingleThreadRef { value: orig.value, _borrow: orig._borrow.clone(), marker: orig.marker }
+    }
+
+    /// Makes a new `SingleThreadRef` for a component of the borrowed data, keeping the
+    /// original shared borrow held.
+    ///
+    /// This is an associated function that needs to be used as `SingleThreadRef::map(...)`,
+    /// since a method would interfere with methods of the same name on the contents of
+    /// `SingleThreadRef` used through `Deref`.
+    pub fn map<U: ?Sized, F>(orig: SingleThreadRef<'b, T>, f: F) -> SingleThreadRef<'b, U>
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        SingleThreadRef { value: NonNull::from(f(&*orig)), _borrow: orig._borrow, marker: orig.marker }
+    }
+
+    /// Makes a new `SingleThreadRef` for a component of the borrowed data, attempting the
+    /// projection and returning the original guard if the closure returns `None`.
+    pub fn filter_map<U: ?Sized, F>(orig: SingleThreadRef<'b, T>, f: F) -> Result<SingleThreadRef<'b, U>, Self>
+    where
+        F: FnOnce(&T) -> Option<&U>,
+    {
+        match f(&*orig) {
+            Some(value) => {
+                Ok(SingleThreadRef { value: NonNull::from(value), _borrow: orig._borrow, marker: orig.marker })
+            }
+            None => Err(orig),
+        }
+    }
+
+    /// Splits a `SingleThreadRef` into two, each borrowing a different component of the
+    /// original data, keeping the original shared borrow held by both.
+    pub fn map_split<U: ?Sized, V: ?Sized, F>(
+        orig: SingleThreadRef<'b, T>,
+        f: F,
+    ) -> (SingleThreadRef<'b, U>, SingleThreadRef<'b, V>)
+    where
+        F: FnOnce(&T) -> (&U, &V),
+    {
+        let (a, b) = f(&*orig);
+        let a = NonNull::from(a);
+        let b = NonNull::from(b);
+        // SAFETY: `orig` already holds a live shared borrow, so the flag currently holds a
+        // valid read count; bump it once more so both halves can independently release it.
+        let cloned = orig._borrow.clone();
+        (
+            SingleThreadRef { value: a, _borrow: cloned, marker: orig.marker },
+            SingleThreadRef { value: b, _borrow: orig._borrow, marker: orig.marker },
+        )
+    }
+}
+
+impl<'b, T: ?Sized> SingleThreadRefMut<'b, T> {
+    /// Makes a new `SingleThreadRefMut` for a component of the borrowed data, keeping the
+    /// original exclusive borrow held.
+    ///
+    /// This is an associated function that needs to be used as `SingleThreadRefMut::map(...)`,
+    /// for the same reason as [`SingleThreadRef::map`].
+    pub fn map<U: ?Sized, F>(orig: SingleThreadRefMut<'b, T>, f: F) -> SingleThreadRefMut<'b, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        // SAFETY: `orig` holds the single exclusive borrow, so this is the only live
+        // reference to the pointee.
+        let value = NonNull::from(f(unsafe { &mut *orig.value.as_ptr() }));
+        SingleThreadRefMut { value, _borrow: orig._borrow, marker: PhantomData }
+    }
+
+    /// Makes a new `SingleThreadRefMut` for a component of the borrowed data, attempting the
+    /// projection and returning the original guard if the closure returns `None`.
+    pub fn filter_map<U: ?Sized, F>(orig: SingleThreadRefMut<'b, T>, f: F) -> Result<SingleThreadRefMut<'b, U>, Self>
+    where
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        // SAFETY: see `map`.
+        match f(unsafe { &mut *orig.value.as_ptr() }) {
+            Some(value) => {
+                let value = NonNull::from(value);
+                Ok(SingleThreadRefMut { value, _borrow: orig._borrow, marker: PhantomData })
+            }
+            None => Err(orig),
+        }
+    }
+
+    /// Splits a `SingleThreadRefMut` into two, each exclusively borrowing a different,
+    /// disjoint component of the original data, sharing the single write borrow.
+    pub fn map_split<U: ?Sized, V: ?Sized, F>(
+        orig: SingleThreadRefMut<'b, T>,
+        f: F,
+    ) -> (SingleThreadRefMut<'b, U>, SingleThreadRefMut<'b, V>)
+    where
+        F: FnOnce(&mut T) -> (&mut U, &mut V),
+    {
+        // SAFETY: see `map`.
+        let (a, b) = f(unsafe { &mut *orig.value.as_ptr() });
+        let a = NonNull::from(a);
+        let b = NonNull::from(b);
+        // SAFETY: `orig` already holds the single write borrow; extend it with one more
+        // level so each half can independently release it on drop.
+        let cloned = orig._borrow.clone();
+        (
+            SingleThreadRefMut { value: a, _borrow: cloned, marker: PhantomData },
+            SingleThreadRefMut { value: b, _borrow: orig._borrow, marker: PhantomData },
+        )
+    }
+}
+
 impl Drop for BorrowRef<'_> {
     #[inline]
     fn drop(&mut self) {
@@ -231,7 +610,12 @@ impl Drop for BorrowRef<'_> {
         unsafe {
             let borrow = *self.borrow.get();
             debug_assert!(is_reading(borrow));
-            *self.borrow.get() = borrow - 1;
+            let borrow = borrow - 1;
+            *self.borrow.get() = borrow;
+            #[cfg(feature = "diagnostics")]
+            if borrow == UNUSED {
+                *self.location.get() = None;
+            }
         }
     }
 }
@@ -244,27 +628,32 @@ impl Drop for BorrowRefMut<'_> {
         unsafe {
             let borrow = *self.borrow.get();
             debug_assert!(is_writing(borrow));
-            *self.borrow.get() = borrow + 1;
+            let borrow = borrow + 1;
+            *self.borrow.get() = borrow;
+            #[cfg(feature = "diagnostics")]
+            if borrow == UNUSED {
+                *self.location.get() = None;
+            }
         }
     }
 }
 
 
-impl<T> Deref for SingleThreadRef<'_, T> {
+impl<T: ?Sized> Deref for SingleThreadRef<'_, T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
         unsafe { self.value.as_ref() }
     }
 }
 
-impl<T> Deref for SingleThreadRefMut<'_, T> {
+impl<T: ?Sized> Deref for SingleThreadRefMut<'_, T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
         unsafe { self.value.as_ref() }
     }
 }
 
-impl<T> DerefMut for SingleThreadRefMut<'_, T> {
+impl<T: ?Sized> DerefMut for SingleThreadRefMut<'_, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe { self.value.as_mut() }
     }
@@ -280,4 +669,90 @@ impl<T: Default> Default for SingleThreadRefCell<T> {
     fn default() -> Self {
         Self::new(Default::default())
     }
-}
\ No newline at end of file
+}
+
+/// A wrapper that lets a value owned on one thread be sent to another thread and then
+/// adopted by it.
+///
+/// `SendCell<T>` is [`Send`] whenever `T` is, just like [`SingleThreadRefCell<T>`] itself --
+/// it does not widen which values can be moved to another thread. Unlike `SingleThreadRefCell`, it never hands out a borrow of the
+/// value while in transit: the only thing you can do with a `SendCell` is [`bind`](SendCell::bind)
+/// it, which consumes it on the thread that should own the resulting cell.
+///
+/// `SendCell` does *not* make a non-`Send` `T` safe to move to another thread: if some other
+/// alias of `T` (e.g. an `Rc` clone made before wrapping) is still live on the original thread,
+/// binding and using it on a second thread races with that alias exactly as it would without
+/// `SendCell` in the picture. The `T: Send` bound on its `Send` impl is what rules that out.
+///
+/// A `SendCell` that is dropped without ever being [`bind`](SendCell::bind)ed is still owned
+/// by the thread that created it, so dropping it anywhere else would drop `T` on the wrong
+/// thread -- exactly the hazard [`SingleThreadRefCell`] not being unconditionally `Send` is
+/// meant to prevent. `SendCell`'s `Drop` impl checks for this and panics instead of silently
+/// dropping `T` on an unauthorized thread.
+pub struct SendCell<T>(ManuallyDrop<SingleThreadRefCell<T>>);
+
+impl<T> SendCell<T> {
+    /// Creates a new `SendCell`, owned by the current thread until it is [`bind`](SendCell::bind)ed.
+    pub fn new(val: T) -> Self {
+        Self(ManuallyDrop::new(SingleThreadRefCell::new(val)))
+    }
+
+    /// Adopts the value on the current thread, producing a [`SingleThreadRefCell`] owned by it.
+    pub fn bind(mut self) -> SingleThreadRefCell<T> {
+        // SAFETY: `inner` is taken out of the `ManuallyDrop` exactly once, and `self` is
+        // forgotten right after so its `Drop` impl never runs on the now-empty wrapper.
+        let mut inner = unsafe { ManuallyDrop::take(&mut self.0) };
+        mem::forget(self);
+        inner.rebind_to_current_thread();
+        inner
+    }
+}
+
+impl<T> Drop for SendCell<T> {
+    fn drop(&mut self) {
+        if self.0.check_same_thread() {
+            // SAFETY: `bind` never runs for a `SendCell` that reaches this `Drop` impl, and
+            // `self.0` is not accessed again afterwards.
+            unsafe { ManuallyDrop::drop(&mut self.0) };
+        } else {
+            panic!(
+                "SendCell dropped on a different thread than it was created on without calling \
+                 SendCell::bind; refusing to drop the inner value on the wrong thread"
+            );
+        }
+    }
+}
+
+// SAFETY: requiring `T: Send` means any other alias of the wrapped value (e.g. a live `Arc`
+// clone) is also safe to access concurrently from another thread, so moving this `SendCell`
+// and later binding it there cannot race with such an alias left behind on the original
+// thread. `SendCell` itself never exposes a borrow of its contents in transit, so sending it
+// only ever moves inert bytes; the value only becomes live again once `bind` rebinds the
+// owner thread on the thread that calls it. If `bind` is never called, `Drop` above refuses
+// to run `T`'s destructor on the wrong thread instead of silently racing with it.
+unsafe impl<T: Send> Send for SendCell<T> {}
+
+// Unsizing a cell behind a pointer (e.g. `&SingleThreadRefCell<[i32; 4]>` ->
+// `&SingleThreadRefCell<[i32]>`, or a concrete type's cell -> `dyn Trait`'s cell) works on
+// stable, since the value is the cell's last field. The impls below additionally let a cell
+// coerce the pointer it holds (e.g. `SingleThreadCell<&[i32; 4]>` -> `SingleThreadCell<&[i32]>`)
+// and let the borrow guards coerce (`SingleThreadRef<'_, [i32; 4]>` -> `SingleThreadRef<'_, [i32]>`).
+// They require the unstable `CoerceUnsized` trait, so they are opt-in behind the `unsize`
+// feature and a nightly toolchain, mirroring how `Cell`/`RefCell`/`UnsafeCell` gained these
+// impls in std.
+#[cfg(feature = "unsize")]
+use std::marker::Unsize;
+#[cfg(feature = "unsize")]
+use std::ops::CoerceUnsized;
+
+#[cfg(feature = "unsize")]
+impl<T: CoerceUnsized<U>, U> CoerceUnsized<SingleThreadCell<U>> for SingleThreadCell<T> {}
+
+#[cfg(feature = "unsize")]
+impl<T: CoerceUnsized<U>, U> CoerceUnsized<SingleThreadRefCell<U>> for SingleThreadRefCell<T> {}
+
+#[cfg(feature = "unsize")]
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<SingleThreadRef<'a, U>> for SingleThreadRef<'a, T> {}
+
+#[cfg(feature = "unsize")]
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<SingleThreadRefMut<'a, U>> for SingleThreadRefMut<'a, T> {}
\ No newline at end of file
@@ -1,14 +1,28 @@
 use std::thread::ThreadId;
 
+use crate::error::BorrowError;
+
 pub trait SingleThreadType {
     fn get_owner_thread_id(&self) -> ThreadId;
 
     /// Check the current thread and panic if not same.
     #[inline]
+    #[track_caller]
     fn check_thread_panic(&self) {
+        if let Err(e) = self.try_check_thread() {
+            e.panic()
+        }
+    }
+
+    /// Check the current thread, returning a [`BorrowError::WrongThread`] instead of panicking.
+    #[inline]
+    fn try_check_thread(&self) -> Result<(), BorrowError> {
         let current_id = std::thread::current().id();
-        if current_id != self.get_owner_thread_id() {
-            panic!("Access single thread cell with different thread id {:?}", current_id);
+        let expected = self.get_owner_thread_id();
+        if current_id != expected {
+            Err(BorrowError::WrongThread { expected, actual: current_id })
+        } else {
+            Ok(())
         }
     }
 
@@ -18,3 +32,23 @@ pub trait SingleThreadType {
         current_id == self.get_owner_thread_id()
     }
 }
+
+/// Opt-in capability for [`SingleThreadType`] implementors that support being handed off to,
+/// and adopted by, another thread (e.g. [`SendCell`](crate::SendCell)).
+///
+/// This is a separate trait, rather than a method on [`SingleThreadType`] itself, so that
+/// adding it never widens the required surface of the base trait for existing implementors.
+pub trait Rebindable: SingleThreadType {
+    /// Overwrite the owner thread. Only implementors that can guarantee exclusive access
+    /// (i.e. a `&mut self` method) should expose this.
+    fn set_owner_thread_id(&mut self, id: ThreadId);
+
+    /// Rebinds the owner thread to the current thread.
+    ///
+    /// Takes `&mut self`, which proves there are no outstanding borrows and no other thread
+    /// can be concurrently accessing the value, so this never needs a runtime check.
+    #[inline]
+    fn rebind_to_current_thread(&mut self) {
+        self.set_owner_thread_id(std::thread::current().id());
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg(test)]
+
+use single_thread_cell::{SingleThreadRef, SingleThreadRefCell};
+
+#[test]
+fn test_single_thread_ref_clone_shares_the_read_count() {
+    let cell = SingleThreadRefCell::new(5);
+    let b1 = cell.borrow();
+    let b2 = SingleThreadRef::clone(&b1);
+
+    assert_eq!(*b1, 5);
+    assert_eq!(*b2, 5);
+
+    // both clones are live reads, so a conflicting mutable borrow still fails.
+    assert!(cell.try_borrow_mut().is_err());
+
+    drop(b1);
+    // one clone is still live.
+    assert!(cell.try_borrow_mut().is_err());
+
+    drop(b2);
+    // no clones remain, so the cell is free to be mutably borrowed again.
+    assert!(cell.try_borrow_mut().is_ok());
+}
+
+#[test]
+fn test_single_thread_ref_clone_is_independent_of_the_original() {
+    let cell = SingleThreadRefCell::new(5);
+    let b1 = cell.borrow();
+    let b2 = SingleThreadRef::clone(&b1);
+    drop(b1);
+
+    // `b2` keeps the borrow alive on its own after `b1` is dropped.
+    assert_eq!(*b2, 5);
+    assert!(cell.try_borrow_mut().is_err());
+}
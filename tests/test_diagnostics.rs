@@ -0,0 +1,58 @@
+#![cfg(all(test, feature = "diagnostics"))]
+
+use single_thread_cell::SingleThreadRefCell;
+
+#[test]
+#[should_panic(expected = "already borrowed; previous borrow at")]
+fn test_diagnostics_reports_previous_borrow_location() {
+    let cell = SingleThreadRefCell::new(0);
+    let _b = cell.borrow();
+    let _ab = cell.borrow_mut();
+}
+
+#[test]
+#[should_panic(expected = "already mutably borrowed; previous borrow at")]
+fn test_diagnostics_reports_previous_mutable_borrow_location() {
+    let cell = SingleThreadRefCell::new(0);
+    let _b = cell.borrow_mut();
+    let _ab = cell.borrow();
+}
+
+#[test]
+#[should_panic(expected = "test_diagnostics.rs")]
+fn test_diagnostics_panic_message_names_this_file() {
+    let cell = SingleThreadRefCell::new(0);
+    let _b = cell.borrow();
+    let _ab = cell.borrow_mut();
+}
+
+#[test]
+fn test_diagnostics_does_not_report_a_dropped_borrow() {
+    let cell = SingleThreadRefCell::new(0);
+
+    let stale = cell.borrow();
+    let stale_line = line!() - 1;
+    drop(stale);
+
+    let fresh = cell.borrow();
+    let fresh_line = line!() - 1;
+
+    let message = *std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        cell.borrow_mut();
+    }))
+    .unwrap_err()
+    .downcast::<String>()
+    .unwrap();
+    drop(fresh);
+
+    let stale_marker = format!(":{stale_line}:");
+    let fresh_marker = format!(":{fresh_line}:");
+    assert!(
+        message.contains(&fresh_marker),
+        "expected panic message to name the still-live borrow's location, got: {message}"
+    );
+    assert!(
+        !message.contains(&stale_marker),
+        "expected panic message not to mention the stale, already-dropped borrow's location, got: {message}"
+    );
+}
@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+use single_thread_cell::{SingleThreadCell, SingleThreadRefCell};
+
+#[test]
+fn test_cell_get_mut() {
+    let mut cell = SingleThreadCell::new(1);
+    *cell.get_mut() += 1;
+    assert_eq!(cell.get(), 2);
+}
+
+#[test]
+fn test_cell_into_inner() {
+    let cell = SingleThreadCell::new(5);
+    assert_eq!(cell.into_inner(), 5);
+}
+
+#[test]
+fn test_cell_take() {
+    let mut cell = SingleThreadCell::new(5);
+    assert_eq!(cell.take(), 5);
+    assert_eq!(cell.get(), 0);
+}
+
+#[test]
+fn test_cell_replace_with() {
+    let mut cell = SingleThreadCell::new(5);
+    let old = cell.replace_with(|v| *v + 1);
+    assert_eq!(old, 5);
+    assert_eq!(cell.get(), 6);
+}
+
+#[test]
+fn test_cell_swap() {
+    let a = SingleThreadCell::new(1);
+    let b = SingleThreadCell::new(2);
+    a.swap(&b);
+    assert_eq!(a.get(), 2);
+    assert_eq!(b.get(), 1);
+}
+
+#[test]
+fn test_cell_swap_with_itself_is_a_no_op() {
+    let a = SingleThreadCell::new(1);
+    a.swap(&a);
+    assert_eq!(a.get(), 1);
+}
+
+#[test]
+fn test_cell_get_mut_and_into_inner_skip_thread_check() {
+    let mut cell = std::sync::Arc::new(SingleThreadCell::new(1));
+    std::thread::spawn(move || {
+        // `&mut self` proves exclusive access, so no thread check is performed.
+        *std::sync::Arc::get_mut(&mut cell).unwrap().get_mut() += 1;
+        assert_eq!(std::sync::Arc::try_unwrap(cell).ok().unwrap().into_inner(), 2);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_ref_cell_get_mut() {
+    let mut cell = SingleThreadRefCell::new(1);
+    *cell.get_mut() += 1;
+    assert_eq!(*cell.borrow(), 2);
+}
+
+#[test]
+fn test_ref_cell_into_inner() {
+    let cell = SingleThreadRefCell::new(5);
+    assert_eq!(cell.into_inner(), 5);
+}
@@ -0,0 +1,97 @@
+#![cfg(test)]
+
+use single_thread_cell::{SingleThreadRef, SingleThreadRefCell, SingleThreadRefMut};
+
+struct Pair {
+    a: i32,
+    b: i32,
+}
+
+#[test]
+fn test_ref_map() {
+    let cell = SingleThreadRefCell::new(Pair { a: 1, b: 2 });
+    let borrowed = SingleThreadRef::map(cell.borrow(), |p| &p.a);
+    assert_eq!(*borrowed, 1);
+
+    // the projected borrow still keeps the whole cell locked.
+    assert!(cell.try_borrow_mut().is_err());
+    drop(borrowed);
+    assert!(cell.try_borrow_mut().is_ok());
+}
+
+#[test]
+fn test_ref_filter_map_some() {
+    let cell = SingleThreadRefCell::new(Some(5));
+    let Ok(borrowed) = SingleThreadRef::filter_map(cell.borrow(), |o| o.as_ref()) else {
+        panic!("expected filter_map to succeed");
+    };
+    assert_eq!(*borrowed, 5);
+}
+
+#[test]
+fn test_ref_filter_map_none() {
+    let cell: SingleThreadRefCell<Option<i32>> = SingleThreadRefCell::new(None);
+    let Err(orig) = SingleThreadRef::filter_map(cell.borrow(), |o| o.as_ref()) else {
+        panic!("expected filter_map to fail");
+    };
+    // the original guard is handed back, still borrowing the cell.
+    assert!(orig.is_none());
+}
+
+#[test]
+fn test_ref_map_split() {
+    let cell = SingleThreadRefCell::new(Pair { a: 1, b: 2 });
+    let (a, b) = SingleThreadRef::map_split(cell.borrow(), |p| (&p.a, &p.b));
+    assert_eq!(*a, 1);
+    assert_eq!(*b, 2);
+
+    assert!(cell.try_borrow_mut().is_err());
+    drop(a);
+    assert!(cell.try_borrow_mut().is_err());
+    drop(b);
+    assert!(cell.try_borrow_mut().is_ok());
+}
+
+#[test]
+fn test_ref_mut_map() {
+    let cell = SingleThreadRefCell::new(Pair { a: 1, b: 2 });
+    {
+        let mut borrowed = SingleThreadRefMut::map(cell.borrow_mut(), |p| &mut p.a);
+        *borrowed += 10;
+    }
+    assert_eq!(cell.borrow().a, 11);
+}
+
+#[test]
+fn test_ref_mut_filter_map_some() {
+    let cell = SingleThreadRefCell::new(Some(5));
+    {
+        let Ok(mut borrowed) = SingleThreadRefMut::filter_map(cell.borrow_mut(), |o| o.as_mut()) else {
+            panic!("expected filter_map to succeed");
+        };
+        *borrowed += 1;
+    }
+    assert_eq!(*cell.borrow(), Some(6));
+}
+
+#[test]
+fn test_ref_mut_filter_map_none() {
+    let cell: SingleThreadRefCell<Option<i32>> = SingleThreadRefCell::new(None);
+    let Err(orig) = SingleThreadRefMut::filter_map(cell.borrow_mut(), |o| o.as_mut()) else {
+        panic!("expected filter_map to fail");
+    };
+    assert!(orig.is_none());
+}
+
+#[test]
+fn test_ref_mut_map_split() {
+    let cell = SingleThreadRefCell::new(Pair { a: 1, b: 2 });
+    {
+        let (mut a, mut b) = SingleThreadRefMut::map_split(cell.borrow_mut(), |p| (&mut p.a, &mut p.b));
+        *a += 10;
+        *b += 20;
+    }
+    let borrowed = cell.borrow();
+    assert_eq!(borrowed.a, 11);
+    assert_eq!(borrowed.b, 22);
+}
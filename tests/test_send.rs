@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use std::marker::PhantomData;
-use single_thread_cell::{SingleThreadCell, SingleThreadRef, SingleThreadRefCell, SingleThreadRefMut};
+use single_thread_cell::{Rebindable, SendCell, SingleThreadCell, SingleThreadRef, SingleThreadRefCell, SingleThreadRefMut};
 
 #[derive(Eq, PartialEq, Debug)]
 struct True;
@@ -46,4 +46,50 @@ fn test_send() {
 
     assert_eq!(is_send!(SingleThreadRef<()>), False);
     assert_eq!(is_send!(SingleThreadRefMut<()>), False);
+
+    // `SendCell<T>` is Send exactly when `T` is -- it does not make a non-Send payload safe
+    // to move to another thread.
+    assert_eq!(is_send!(SendCell<i32>), True);
+    assert_eq!(is_send!(SendCell<Rc<()>>), False);
+}
+
+#[test]
+fn test_rebind_to_current_thread() {
+    let mut cell = SingleThreadCell::new(0);
+    std::thread::spawn(move || {
+        cell.rebind_to_current_thread();
+        cell.set(1);
+        assert_eq!(cell.get(), 1);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_send_cell_bind() {
+    let cell = SendCell::new(0);
+    std::thread::spawn(move || {
+        let bound = cell.bind();
+        assert_eq!(*bound.borrow(), 0);
+        *bound.borrow_mut() = 42;
+        assert_eq!(*bound.borrow(), 42);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_send_cell_dropped_without_bind_on_another_thread_panics() {
+    let cell = SendCell::new(0);
+    let result = std::thread::spawn(move || {
+        drop(cell);
+    })
+    .join();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_send_cell_dropped_without_bind_on_same_thread_is_fine() {
+    let cell = SendCell::new(0);
+    drop(cell);
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+use single_thread_cell::{BorrowError, SingleThreadCell, SingleThreadRefCell};
+
+#[test]
+fn test_try_get_set_replace() {
+    let cell = SingleThreadCell::new(0);
+    assert_eq!(cell.try_get(), Ok(0));
+    assert_eq!(cell.try_set(1), Ok(()));
+    assert_eq!(cell.try_get(), Ok(1));
+    assert_eq!(cell.try_replace(2), Ok(1));
+    assert_eq!(cell.try_get(), Ok(2));
+}
+
+#[test]
+fn test_try_get_set_replace_wrong_thread() {
+    let cell = std::sync::Arc::new(SingleThreadCell::new(0));
+    let cloned = cell.clone();
+    std::thread::spawn(move || {
+        assert!(matches!(cloned.try_get(), Err(BorrowError::WrongThread { .. })));
+        assert!(matches!(cloned.try_set(1), Err(BorrowError::WrongThread { .. })));
+        assert!(matches!(cloned.try_replace(1), Err(BorrowError::WrongThread { .. })));
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_try_borrow_success() {
+    let cell = SingleThreadRefCell::new(0);
+    assert_eq!(*cell.try_borrow().unwrap(), 0);
+
+    *cell.try_borrow_mut().unwrap() = 1;
+    assert_eq!(*cell.try_borrow().unwrap(), 1);
+
+    let b1 = cell.try_borrow().unwrap();
+    let b2 = cell.try_borrow().unwrap();
+    assert_eq!(*b1, 1);
+    assert_eq!(*b2, 1);
+}
+
+#[test]
+fn test_try_borrow_already_borrowed() {
+    let cell = SingleThreadRefCell::new(0);
+    let _b = cell.borrow();
+    assert_eq!(cell.try_borrow_mut().err(), Some(BorrowError::AlreadyBorrowed));
+}
+
+#[test]
+fn test_try_borrow_mut_already_mutably_borrowed() {
+    let cell = SingleThreadRefCell::new(0);
+    let _b = cell.borrow_mut();
+    assert_eq!(cell.try_borrow().err(), Some(BorrowError::AlreadyMutablyBorrowed));
+    assert_eq!(cell.try_borrow_mut().err(), Some(BorrowError::AlreadyBorrowed));
+}
+
+#[test]
+fn test_try_borrow_wrong_thread() {
+    let cell = std::sync::Arc::new(SingleThreadRefCell::new(0));
+    let cloned = cell.clone();
+    std::thread::spawn(move || {
+        assert!(matches!(cloned.try_borrow(), Err(BorrowError::WrongThread { .. })));
+        assert!(matches!(cloned.try_borrow_mut(), Err(BorrowError::WrongThread { .. })));
+    })
+    .join()
+    .unwrap();
+}
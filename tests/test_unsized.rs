@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+use single_thread_cell::{SingleThreadCell, SingleThreadRefCell};
+
+trait Greet {
+    fn greet(&self) -> String;
+}
+
+struct Hello;
+
+impl Greet for Hello {
+    fn greet(&self) -> String {
+        "hello".to_string()
+    }
+}
+
+#[test]
+fn test_ref_cell_unsizes_to_dyn_trait() {
+    let concrete = SingleThreadRefCell::new(Hello);
+    let dynamic: &SingleThreadRefCell<dyn Greet> = &concrete;
+    assert_eq!(dynamic.borrow().greet(), "hello");
+}
+
+#[test]
+fn test_ref_cell_unsizes_to_slice() {
+    let arr = SingleThreadRefCell::new([1i32, 2, 3, 4]);
+    let slice: &SingleThreadRefCell<[i32]> = &arr;
+    assert_eq!(&*slice.borrow(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn test_cell_unsizes_to_dyn_trait() {
+    let mut concrete = SingleThreadCell::new(Hello);
+    let dynamic: &mut SingleThreadCell<dyn Greet> = &mut concrete;
+    assert_eq!(dynamic.get_mut().greet(), "hello");
+}
+
+#[cfg(feature = "unsize")]
+#[test]
+fn test_box_coerces_through_ref_cell() {
+    let boxed: Box<SingleThreadRefCell<[i32; 4]>> = Box::new(SingleThreadRefCell::new([1, 2, 3, 4]));
+    let boxed: Box<SingleThreadRefCell<[i32]>> = boxed;
+    assert_eq!(&*boxed.borrow(), &[1, 2, 3, 4]);
+}
+
+#[cfg(feature = "unsize")]
+#[test]
+fn test_rc_coerces_through_ref_cell_dyn_trait() {
+    use std::rc::Rc;
+
+    let rc: Rc<SingleThreadRefCell<Hello>> = Rc::new(SingleThreadRefCell::new(Hello));
+    let rc: Rc<SingleThreadRefCell<dyn Greet>> = rc;
+    assert_eq!(rc.borrow().greet(), "hello");
+}